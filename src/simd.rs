@@ -0,0 +1,166 @@
+//! AVX2 fast paths for hex encode/decode, gated behind a runtime
+//! `is_x86_feature_detected!("avx2")` check in `main.rs`. Each entry point
+//! falls back to the scalar table path for the tail that doesn't fill a
+//! full 32-byte vector, and for the whole input if any invalid byte is
+//! encountered (so error reporting stays in one place).
+
+use std::arch::x86_64::*;
+
+use crate::{bytes_to_hex_scalar, parse_hex_scalar};
+
+/// Encode `bytes` to a hex string using `alphabet`, 32 input bytes (64
+/// output characters) at a time.
+///
+/// # Safety
+/// Caller must ensure the `avx2` target feature is available.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn bytes_to_hex_avx2(bytes: &[u8], alphabet: &[u8; 16]) -> String {
+    let mut out = Vec::with_capacity(bytes.len() * 2);
+
+    let chunks = bytes.chunks_exact(32);
+    let remainder = chunks.remainder();
+
+    let lut = _mm256_broadcastsi128_si256(_mm_loadu_si128(alphabet.as_ptr() as *const __m128i));
+    let mask_lo = _mm256_set1_epi8(0x0F);
+
+    for chunk in chunks {
+        let data = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        let lo_nibbles = _mm256_and_si256(data, mask_lo);
+        let hi_nibbles = _mm256_and_si256(_mm256_srli_epi16(data, 4), mask_lo);
+
+        let hi_hex = _mm256_shuffle_epi8(lut, hi_nibbles);
+        let lo_hex = _mm256_shuffle_epi8(lut, lo_nibbles);
+
+        // unpacklo/hi interleave hi/lo hex digits within each 128-bit lane;
+        // permute2x128 puts the two lanes back in sequential output order.
+        let interleaved_lo = _mm256_unpacklo_epi8(hi_hex, lo_hex);
+        let interleaved_hi = _mm256_unpackhi_epi8(hi_hex, lo_hex);
+        let first = _mm256_permute2x128_si256(interleaved_lo, interleaved_hi, 0x20);
+        let second = _mm256_permute2x128_si256(interleaved_lo, interleaved_hi, 0x31);
+
+        let mut buf = [0u8; 64];
+        _mm256_storeu_si256(buf.as_mut_ptr() as *mut __m256i, first);
+        _mm256_storeu_si256(buf[32..].as_mut_ptr() as *mut __m256i, second);
+        out.extend_from_slice(&buf);
+    }
+
+    out.extend_from_slice(bytes_to_hex_scalar(remainder, alphabet).as_bytes());
+
+    // Safety: every byte written above comes from `alphabet`, which is ASCII.
+    unsafe { String::from_utf8_unchecked(out) }
+}
+
+/// Decode an ASCII hex string to bytes, 32 input characters (16 output
+/// bytes) at a time.
+///
+/// # Safety
+/// Caller must ensure the `avx2` target feature is available.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn parse_hex_avx2(input: &[u8]) -> Result<Vec<u8>, String> {
+    let full_len = input.len() - input.len() % 32;
+
+    let ascii_0 = _mm256_set1_epi8(b'0' as i8);
+    let ten = _mm256_set1_epi8(10);
+    let nine = _mm256_set1_epi8(9);
+    let sixteen = _mm256_set1_epi8(16);
+    let neg_one = _mm256_set1_epi8(-1);
+    let offset_upper = _mm256_set1_epi8((b'A' - 10) as i8);
+    let offset_lower = _mm256_set1_epi8((b'a' - 10) as i8);
+
+    let mut out = Vec::with_capacity(input.len() / 2);
+
+    for chunk in input[..full_len].chunks_exact(32) {
+        let data = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+
+        // Subtract/compare/blend: try decoding each byte as a '0'-'9' digit,
+        // then as an upper- or lower-case 'A'-'F'/'a'-'f' letter, and blend
+        // in whichever interpretation is in range.
+        let digit = _mm256_sub_epi8(data, ascii_0);
+        let is_digit = _mm256_and_si256(
+            _mm256_cmpgt_epi8(ten, digit),
+            _mm256_cmpgt_epi8(digit, neg_one),
+        );
+
+        let upper = _mm256_sub_epi8(data, offset_upper);
+        let is_upper = _mm256_and_si256(_mm256_cmpgt_epi8(upper, nine), _mm256_cmpgt_epi8(sixteen, upper));
+
+        let lower = _mm256_sub_epi8(data, offset_lower);
+        let is_lower = _mm256_and_si256(_mm256_cmpgt_epi8(lower, nine), _mm256_cmpgt_epi8(sixteen, lower));
+
+        let nibble = _mm256_blendv_epi8(_mm256_blendv_epi8(digit, upper, is_upper), lower, is_lower);
+        let valid = _mm256_or_si256(_mm256_or_si256(is_digit, is_upper), is_lower);
+
+        if _mm256_movemask_epi8(valid) != -1 {
+            // Invalid byte somewhere in this chunk: fall back to the scalar
+            // path over the whole input so the error message names the
+            // exact offending pair.
+            return parse_hex_scalar(input);
+        }
+
+        let mut nibbles = [0u8; 32];
+        _mm256_storeu_si256(nibbles.as_mut_ptr() as *mut __m256i, nibble);
+        for pair in nibbles.chunks_exact(2) {
+            out.push((pair[0] << 4) | pair[1]);
+        }
+    }
+
+    out.extend_from_slice(&parse_hex_scalar(&input[full_len..])?);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Lengths that straddle the 32-byte decode / 32-byte encode chunk
+    // boundary on both sides, plus 0 and 1 for the fully-scalar fallback.
+    const LENGTHS: &[usize] = &[0, 1, 2, 3, 15, 16, 17, 31, 32, 33, 63, 64, 65];
+
+    fn sample_bytes(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 7 + 1) as u8).collect()
+    }
+
+    #[test]
+    fn bytes_to_hex_avx2_matches_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        for &len in LENGTHS {
+            let bytes = sample_bytes(len);
+            let avx2 = unsafe { bytes_to_hex_avx2(&bytes, crate::HEX_LOWER) };
+            let scalar = bytes_to_hex_scalar(&bytes, crate::HEX_LOWER);
+            assert_eq!(avx2, scalar, "mismatch at len {len}");
+        }
+    }
+
+    #[test]
+    fn parse_hex_avx2_matches_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        for &len in LENGTHS {
+            // Even lengths only: parse_hex_avx2, like parse_hex_scalar,
+            // assumes a whole number of hex pairs.
+            let len = len - len % 2;
+            let hex = bytes_to_hex_scalar(&sample_bytes(len / 2), crate::HEX_LOWER);
+            let avx2 = unsafe { parse_hex_avx2(hex.as_bytes()) };
+            let scalar = parse_hex_scalar(hex.as_bytes());
+            assert_eq!(avx2, scalar, "mismatch at len {len}");
+        }
+    }
+
+    #[test]
+    fn parse_hex_avx2_reports_invalid_byte_mid_chunk() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        // 32 valid hex chars with a 'g' injected mid-chunk, forcing the
+        // AVX2 path to fall back to the scalar path for error reporting.
+        let mut hex = "a".repeat(32).into_bytes();
+        hex[20] = b'g';
+        let avx2 = unsafe { parse_hex_avx2(&hex) };
+        let scalar = parse_hex_scalar(&hex);
+        assert_eq!(avx2, scalar);
+        assert!(avx2.is_err());
+    }
+}